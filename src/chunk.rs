@@ -1,6 +1,9 @@
 use std::convert::TryFrom;
+use std::io::Write;
 //use std::fmt;
 
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine as _;
 use crc;
 
 use crate::chunk_type::ChunkType;
@@ -21,6 +24,10 @@ impl Chunk {
     pub const METADATA_SIZE: usize =
         Chunk::DATA_LENGTH_SIZE + Chunk::CHUNK_TYPE_SIZE + Chunk::CRC_SIZE;
 
+    pub const ARMOR_LINE_WIDTH: usize = 76;
+    pub const ARMOR_HEADER: &'static str = "-----BEGIN PNG CHUNK-----";
+    pub const ARMOR_FOOTER: &'static str = "-----END-----";
+
     pub fn new(chunk_type: ChunkType, data: Vec<u8>) -> Chunk {
         Self { chunk_type, data }
     }
@@ -34,15 +41,10 @@ impl Chunk {
     }
 
     pub fn crc(&self) -> u32 {
-        let bytes: Vec<u8> = self
-            .chunk_type
-            .bytes()
-            .iter()
-            .chain(self.data.iter())
-            .copied()
-            .collect();
-
-        IEEE.checksum(&bytes)
+        let mut digest = IEEE.digest();
+        digest.update(&self.chunk_type.bytes());
+        digest.update(&self.data);
+        digest.finalize()
     }
 
     pub fn data(&self) -> &[u8] {
@@ -54,16 +56,100 @@ impl Chunk {
         Ok(s.to_string())
     }
 
+    /// Base64-encodes just the chunk's payload, independent of its
+    /// validity as UTF-8. Unlike [`Chunk::data_as_string`], this always
+    /// succeeds since it makes no assumption about the payload's encoding.
+    pub fn data_as_base64(&self) -> String {
+        BASE64.encode(&self.data)
+    }
+
     pub fn as_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.encoded_len());
+        self.write_to(&mut bytes)
+            .expect("writing to a Vec<u8> cannot fail");
+        bytes
+    }
+
+    /// Wraps the chunk's full `as_bytes()` form as line-wrapped base64
+    /// text, bracketed by a begin/end envelope, so it can be copy-pasted
+    /// through text-only channels and reconstructed with
+    /// [`Chunk::from_armored_str`].
+    pub fn to_armored_string(&self) -> String {
+        let encoded = BASE64.encode(self.as_bytes());
+        let mut armored = String::from(Self::ARMOR_HEADER);
+        armored.push('\n');
+        for line in encoded.as_bytes().chunks(Self::ARMOR_LINE_WIDTH) {
+            armored.push_str(std::str::from_utf8(line).expect("base64 output is ASCII"));
+            armored.push('\n');
+        }
+        armored.push_str(Self::ARMOR_FOOTER);
+
+        armored
+    }
+
+    /// Parses a chunk back out of the text produced by
+    /// [`Chunk::to_armored_string`].
+    pub fn from_armored_str(s: &str) -> Result<Chunk> {
+        let mut lines = s.lines();
+
+        match lines.next() {
+            Some(line) if line.trim() == Self::ARMOR_HEADER => {}
+            _ => return Err(format!("missing armor header '{}'", Self::ARMOR_HEADER).into()),
+        }
+
+        let mut encoded = String::new();
+        for line in lines {
+            if line.trim() == Self::ARMOR_FOOTER {
+                let bytes = BASE64.decode(encoded)?;
+                return Chunk::try_from(bytes.as_slice());
+            }
+            encoded.push_str(line.trim());
+        }
+
+        Err(format!("missing armor footer '{}'", Self::ARMOR_FOOTER).into())
+    }
+
+    /// Builds a chunk whose payload packs `fields` via
+    /// [`crate::payload::encode_list`], so several logical values (a
+    /// sender tag, a timestamp, a message, ...) can share one chunk.
+    pub fn with_fields(chunk_type: ChunkType, fields: &[&[u8]]) -> Chunk {
+        Self::new(chunk_type, crate::payload::encode_list(fields))
+    }
+
+    /// Unpacks a payload built with [`Chunk::with_fields`] back into its
+    /// individual byte-strings.
+    pub fn fields(&self) -> Result<Vec<Vec<u8>>> {
+        crate::payload::decode_list(&self.data)
+    }
+}
+
+/// A trait for types that can be streamed out as bytes.
+///
+/// Modeled on the `der` crate's `Encode` trait, which pairs an
+/// `encoded_len()` with the write itself so callers can size a buffer
+/// (or skip allocating one entirely) before encoding.
+pub trait Encode {
+    /// The number of bytes `write_to` will write.
+    fn encoded_len(&self) -> usize;
+
+    /// Streams the encoded form directly to `w`, without building an
+    /// intermediate buffer. Returns the number of bytes written.
+    fn write_to<W: Write>(&self, w: &mut W) -> Result<usize>;
+}
+
+impl Encode for Chunk {
+    fn encoded_len(&self) -> usize {
+        Chunk::METADATA_SIZE + self.data.len()
+    }
+
+    fn write_to<W: Write>(&self, w: &mut W) -> Result<usize> {
         let data_length = self.data.len() as u32;
-        data_length
-            .to_be_bytes()
-            .iter()
-            .chain(self.chunk_type.bytes().iter())
-            .chain(self.data.iter())
-            .chain(self.crc().to_be_bytes().iter())
-            .copied()
-            .collect()
+        w.write_all(&data_length.to_be_bytes())?;
+        w.write_all(&self.chunk_type.bytes())?;
+        w.write_all(&self.data)?;
+        w.write_all(&self.crc().to_be_bytes())?;
+
+        Ok(self.encoded_len())
     }
 }
 
@@ -71,23 +157,44 @@ impl TryFrom<&[u8]> for Chunk {
     type Error = crate::Error;
 
     fn try_from(value: &[u8]) -> std::result::Result<Self, Self::Error> {
-        let (data_length, value) = value.split_at(Chunk::DATA_LENGTH_SIZE);
-        let data_length = u32::from_be_bytes(data_length.try_into()?) as usize;
+        let mut reader = ChunkReader::new(value);
+        let chunk = Chunk::decode(&mut reader)?;
+        reader.finish()?;
+        Ok(chunk)
+    }
+}
 
-        let (chunk_type_bytes, value) = value.split_at(Chunk::CHUNK_TYPE_SIZE);
-        let chunk_type_bytes: [u8; 4] = chunk_type_bytes.try_into()?;
-        let chunk_type: ChunkType = ChunkType::try_from(chunk_type_bytes)?;
+/// A trait for types that can be decoded from a [`ChunkReader`].
+///
+/// Modeled on the `Decode`/`Reader` split used by the `der` crate: a type
+/// knows how to read itself off a cursor, and the cursor knows nothing
+/// about the type it's producing.
+pub trait Decode: Sized {
+    fn decode(reader: &mut ChunkReader) -> Result<Self>;
+}
+
+impl Decode for Chunk {
+    fn decode(reader: &mut ChunkReader) -> Result<Self> {
+        let data_length = reader.read_u32_be()? as usize;
+
+        let chunk_type_bytes: [u8; 4] = reader.read_bytes(Chunk::CHUNK_TYPE_SIZE)?.try_into()?;
+        let chunk_type = ChunkType::try_from(chunk_type_bytes)?;
         if !chunk_type.is_valid() {
             return Err(format!("Invalid chunk type '{:?}'!", chunk_type).into());
         }
 
-        let (data, value) = value.split_at(data_length);
-        let (crc_bytes, _) = value.split_at(Chunk::CRC_SIZE);
+        let offset = reader.position();
+        if data_length > reader.remaining() {
+            return Err(format!(
+                "truncated chunk: expected {data_length} data bytes at offset {offset}, found {}",
+                reader.remaining()
+            )
+            .into());
+        }
+        let data = reader.read_bytes(data_length)?.to_vec();
+        let crc_bytes = reader.read_bytes(Chunk::CRC_SIZE)?;
 
-        let new = Self {
-            chunk_type,
-            data: data.into(),
-        };
+        let new = Self { chunk_type, data };
 
         let actual_crc = new.crc();
         let expected_crc = u32::from_be_bytes(crc_bytes.try_into()?);
@@ -102,6 +209,90 @@ impl TryFrom<&[u8]> for Chunk {
     }
 }
 
+/// A cursor over a byte slice that tracks how much of it has been consumed.
+///
+/// `ChunkReader` gives [`Chunk::decode`] a non-allocating way to pull
+/// fixed-size fields off the front of a buffer, erroring out with the
+/// offset of the failing read rather than an opaque `try_into` failure.
+pub struct ChunkReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> ChunkReader<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    /// The number of bytes consumed so far.
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// The number of bytes left to read.
+    pub fn remaining(&self) -> usize {
+        self.data.len() - self.pos
+    }
+
+    pub fn read_u32_be(&mut self) -> Result<u32> {
+        let bytes = self.read_bytes(4)?;
+        Ok(u32::from_be_bytes(bytes.try_into()?))
+    }
+
+    pub fn read_bytes(&mut self, len: usize) -> Result<&'a [u8]> {
+        if len > self.remaining() {
+            return Err(format!(
+                "truncated chunk: expected {len} bytes at offset {}, found {}",
+                self.pos,
+                self.remaining()
+            )
+            .into());
+        }
+
+        let bytes = &self.data[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    /// Errors if any bytes remain unconsumed.
+    pub fn finish(self) -> Result<()> {
+        if self.remaining() != 0 {
+            return Err(format!(
+                "trailing data: {} unconsumed byte(s) remain at offset {}",
+                self.remaining(),
+                self.pos
+            )
+            .into());
+        }
+        Ok(())
+    }
+
+    /// Decodes a back-to-back sequence of chunks from a full PNG body,
+    /// stopping cleanly once the reader is exhausted.
+    pub fn chunks(self) -> Chunks<'a> {
+        Chunks { reader: self }
+    }
+}
+
+/// Iterator over the chunks decoded from a [`ChunkReader`].
+///
+/// Yielded by [`ChunkReader::chunks`].
+pub struct Chunks<'a> {
+    reader: ChunkReader<'a>,
+}
+
+impl Iterator for Chunks<'_> {
+    type Item = Result<Chunk>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.reader.remaining() == 0 {
+            return None;
+        }
+
+        Some(Chunk::decode(&mut self.reader))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -219,6 +410,126 @@ mod tests {
         assert!(chunk.is_err());
     }
 
+    #[test]
+    fn test_chunk_from_bytes_with_trailing_data_errs() {
+        let chunk = testing_chunk();
+        let mut chunk_data = chunk.as_bytes();
+        chunk_data.push(0);
+
+        let chunk = Chunk::try_from(chunk_data.as_ref());
+        assert!(chunk.is_err());
+    }
+
+    #[test]
+    fn test_chunk_from_bytes_truncated_errs() {
+        let chunk = testing_chunk();
+        let chunk_data = chunk.as_bytes();
+        let truncated = &chunk_data[..chunk_data.len() - 5];
+
+        let err = Chunk::try_from(truncated).unwrap_err();
+        assert!(err.to_string().contains("truncated chunk"));
+    }
+
+    #[test]
+    fn test_chunk_data_as_base64() {
+        let chunk = testing_chunk();
+        let decoded = BASE64.decode(chunk.data_as_base64()).unwrap();
+        assert_eq!(decoded, chunk.data());
+    }
+
+    #[test]
+    fn test_armored_round_trip() {
+        let chunk = testing_chunk();
+        let armored = chunk.to_armored_string();
+
+        assert!(armored.starts_with(Chunk::ARMOR_HEADER));
+        assert!(armored.ends_with(Chunk::ARMOR_FOOTER));
+
+        let decoded = Chunk::from_armored_str(&armored).unwrap();
+        assert_eq!(decoded, chunk);
+    }
+
+    #[test]
+    fn test_armored_round_trip_binary_payload() {
+        let chunk_type = ChunkType::from_str("ruSt").unwrap();
+        let data: Vec<u8> = (0u8..=255).collect();
+        let chunk = Chunk::new(chunk_type, data);
+
+        let armored = chunk.to_armored_string();
+        let decoded = Chunk::from_armored_str(&armored).unwrap();
+
+        assert_eq!(decoded, chunk);
+    }
+
+    #[test]
+    fn test_from_armored_str_missing_header_errs() {
+        let chunk = Chunk::from_armored_str("not an armored chunk\n-----END-----");
+        assert!(chunk.is_err());
+    }
+
+    #[test]
+    fn test_from_armored_str_missing_footer_errs() {
+        let armored = format!("{}\nAAAA", Chunk::ARMOR_HEADER);
+        let chunk = Chunk::from_armored_str(&armored);
+        assert!(chunk.is_err());
+    }
+
+    #[test]
+    fn test_with_fields_round_trip() {
+        let chunk_type = ChunkType::from_str("RuSt").unwrap();
+        let sender = b"alice".as_ref();
+        let timestamp = b"1234567890".as_ref();
+        let message = b"meet at dawn".as_ref();
+
+        let chunk = Chunk::with_fields(chunk_type, &[sender, timestamp, message]);
+        let fields = chunk.fields().unwrap();
+
+        assert_eq!(
+            fields,
+            vec![sender.to_vec(), timestamp.to_vec(), message.to_vec()]
+        );
+    }
+
+    #[test]
+    fn test_fields_on_non_rlp_data_errs() {
+        let chunk_type = ChunkType::from_str("RuSt").unwrap();
+        let chunk = Chunk::new(chunk_type, b"not an rlp list".to_vec());
+        assert!(chunk.fields().is_err());
+    }
+
+    #[test]
+    fn test_encoded_len_matches_as_bytes() {
+        let chunk = testing_chunk();
+        assert_eq!(chunk.encoded_len(), chunk.as_bytes().len());
+    }
+
+    #[test]
+    fn test_write_to_matches_as_bytes() {
+        let chunk = testing_chunk();
+        let mut written = Vec::new();
+        let n = chunk.write_to(&mut written).unwrap();
+
+        assert_eq!(n, chunk.encoded_len());
+        assert_eq!(written, chunk.as_bytes());
+    }
+
+    #[test]
+    fn test_chunk_reader_chunks_iterator() {
+        let first = testing_chunk();
+        let second = Chunk::new(
+            ChunkType::from_str("RuSt").unwrap(),
+            "another message".as_bytes().to_vec(),
+        );
+
+        let mut body = first.as_bytes();
+        body.extend(second.as_bytes());
+
+        let chunks: Result<Vec<Chunk>> = ChunkReader::new(&body).chunks().collect();
+        let chunks = chunks.unwrap();
+
+        assert_eq!(chunks, vec![first, second]);
+    }
+
     #[test]
     pub fn test_chunk_trait_impls() {
         let data_length: u32 = 42;
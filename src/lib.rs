@@ -0,0 +1,7 @@
+pub mod chunk;
+pub mod chunk_type;
+pub mod payload;
+pub mod png;
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = std::result::Result<T, Error>;
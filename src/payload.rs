@@ -0,0 +1,228 @@
+//! A small recursive length-prefix codec, modeled on Ethereum's RLP, for
+//! packing a list of byte-strings into a single chunk's opaque `data`.
+//!
+//! # Encoding
+//!
+//! - A single byte in `0x00..=0x7f` encodes itself.
+//! - A byte-string of length `0..=55` is prefixed by `0x80 + len`.
+//! - A longer byte-string is prefixed by `0xb7 + len_of_len`, followed by
+//!   the big-endian length and then the bytes.
+//! - A list whose concatenated-item payload is `0..=55` bytes is prefixed
+//!   by `0xc0 + payload_len`.
+//! - A longer list is prefixed by `0xf7 + len_of_len`, followed by the
+//!   big-endian payload length and then the items.
+
+use crate::Result;
+
+const SHORT_STRING_BASE: u8 = 0x80;
+const SHORT_STRING_MAX_LEN: usize = 55;
+const LONG_STRING_BASE: u8 = 0xb7;
+const SHORT_LIST_BASE: u8 = 0xc0;
+const SHORT_LIST_MAX_LEN: usize = 55;
+const LONG_LIST_BASE: u8 = 0xf7;
+
+/// Encodes a list of byte-strings as a single RLP-style list.
+pub fn encode_list(items: &[&[u8]]) -> Vec<u8> {
+    let mut payload = Vec::new();
+    for item in items {
+        encode_item(item, &mut payload);
+    }
+    prefix_payload(&payload, SHORT_LIST_BASE, LONG_LIST_BASE, SHORT_LIST_MAX_LEN)
+}
+
+/// Decodes a single RLP-style list back into its byte-string items.
+///
+/// Errors if the declared length overruns the buffer or if trailing bytes
+/// follow the list.
+pub fn decode_list(data: &[u8]) -> Result<Vec<Vec<u8>>> {
+    let (items, consumed) = decode_list_at(data, 0)?;
+    if consumed != data.len() {
+        return Err(format!(
+            "trailing bytes after RLP list: {} byte(s) unconsumed",
+            data.len() - consumed
+        )
+        .into());
+    }
+    Ok(items)
+}
+
+fn encode_item(item: &[u8], out: &mut Vec<u8>) {
+    if item.len() == 1 && item[0] <= 0x7f {
+        out.push(item[0]);
+    } else {
+        out.extend(prefix_payload(
+            item,
+            SHORT_STRING_BASE,
+            LONG_STRING_BASE,
+            SHORT_STRING_MAX_LEN,
+        ));
+    }
+}
+
+fn prefix_payload(payload: &[u8], short_base: u8, long_base: u8, short_max_len: usize) -> Vec<u8> {
+    let mut out = Vec::with_capacity(payload.len() + 9);
+    if payload.len() <= short_max_len {
+        out.push(short_base + payload.len() as u8);
+    } else {
+        let len_bytes = be_bytes(payload.len());
+        out.push(long_base + len_bytes.len() as u8);
+        out.extend_from_slice(&len_bytes);
+    }
+    out.extend_from_slice(payload);
+    out
+}
+
+fn decode_list_at(data: &[u8], pos: usize) -> Result<(Vec<Vec<u8>>, usize)> {
+    let prefix = *data
+        .get(pos)
+        .ok_or("unexpected end of input while decoding RLP list")?;
+
+    let (payload_start, payload_len) = if (SHORT_LIST_BASE..=LONG_LIST_BASE).contains(&prefix) {
+        (pos + 1, (prefix - SHORT_LIST_BASE) as usize)
+    } else if prefix > LONG_LIST_BASE {
+        let len_of_len = (prefix - LONG_LIST_BASE) as usize;
+        let len_start = pos + 1;
+        let len = read_length(data, len_start, len_of_len)?;
+        (len_start + len_of_len, len)
+    } else {
+        return Err(format!("expected an RLP list, found item prefix 0x{prefix:02x}").into());
+    };
+
+    let payload_end = checked_end(data.len(), payload_start, payload_len)?;
+
+    let mut items = Vec::new();
+    let mut item_pos = payload_start;
+    while item_pos < payload_end {
+        let (item, next) = decode_item_at(data, item_pos, payload_end)?;
+        items.push(item);
+        item_pos = next;
+    }
+
+    Ok((items, payload_end))
+}
+
+fn decode_item_at(data: &[u8], pos: usize, end: usize) -> Result<(Vec<u8>, usize)> {
+    let prefix = *data
+        .get(pos)
+        .ok_or("unexpected end of input while decoding RLP item")?;
+
+    if prefix <= 0x7f {
+        return Ok((vec![prefix], pos + 1));
+    }
+
+    let (data_start, len) = if (SHORT_STRING_BASE..=LONG_STRING_BASE).contains(&prefix) {
+        (pos + 1, (prefix - SHORT_STRING_BASE) as usize)
+    } else if prefix > LONG_STRING_BASE && prefix < SHORT_LIST_BASE {
+        let len_of_len = (prefix - LONG_STRING_BASE) as usize;
+        let len_start = pos + 1;
+        let len = read_length(data, len_start, len_of_len)?;
+        (len_start + len_of_len, len)
+    } else {
+        return Err(format!("expected an RLP item, found list prefix 0x{prefix:02x}").into());
+    };
+
+    let data_end = checked_end(end, data_start, len)?;
+    Ok((data[data_start..data_end].to_vec(), data_end))
+}
+
+fn read_length(data: &[u8], start: usize, len_of_len: usize) -> Result<usize> {
+    let end = start
+        .checked_add(len_of_len)
+        .filter(|&end| end <= data.len())
+        .ok_or("truncated RLP length prefix")?;
+
+    let len_bytes = &data[start..end];
+    if len_bytes.len() > std::mem::size_of::<usize>() {
+        return Err("declared RLP length too large for this platform".into());
+    }
+
+    let mut buf = [0u8; std::mem::size_of::<usize>()];
+    buf[std::mem::size_of::<usize>() - len_bytes.len()..].copy_from_slice(len_bytes);
+    Ok(usize::from_be_bytes(buf))
+}
+
+fn checked_end(limit: usize, start: usize, len: usize) -> Result<usize> {
+    let end = start
+        .checked_add(len)
+        .filter(|&end| end <= limit)
+        .ok_or_else(|| {
+            format!(
+                "declared RLP length {len} exceeds available {} byte(s)",
+                limit.saturating_sub(start)
+            )
+        })?;
+    Ok(end)
+}
+
+fn be_bytes(mut n: usize) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    while n > 0 {
+        bytes.push((n & 0xff) as u8);
+        n >>= 8;
+    }
+    bytes.reverse();
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip_empty_list() {
+        let encoded = encode_list(&[]);
+        assert_eq!(decode_list(&encoded).unwrap(), Vec::<Vec<u8>>::new());
+    }
+
+    #[test]
+    fn test_round_trip_single_byte_item() {
+        let encoded = encode_list(&[&[0x42]]);
+        assert_eq!(encoded, vec![0xc1, 0x42]);
+        assert_eq!(decode_list(&encoded).unwrap(), vec![vec![0x42]]);
+    }
+
+    #[test]
+    fn test_round_trip_short_string_item() {
+        let items: &[&[u8]] = &[b"sender", b"hello"];
+        let encoded = encode_list(items);
+        let decoded = decode_list(&encoded).unwrap();
+        assert_eq!(decoded, vec![b"sender".to_vec(), b"hello".to_vec()]);
+    }
+
+    #[test]
+    fn test_round_trip_long_string_item() {
+        let long_item = vec![7u8; 200];
+        let encoded = encode_list(&[&long_item]);
+        let decoded = decode_list(&encoded).unwrap();
+        assert_eq!(decoded, vec![long_item]);
+    }
+
+    #[test]
+    fn test_round_trip_long_list() {
+        let items: Vec<Vec<u8>> = (0..20).map(|i| vec![i as u8; 10]).collect();
+        let refs: Vec<&[u8]> = items.iter().map(Vec::as_slice).collect();
+        let encoded = encode_list(&refs);
+
+        assert!(encoded[0] > LONG_LIST_BASE);
+        assert_eq!(decode_list(&encoded).unwrap(), items);
+    }
+
+    #[test]
+    fn test_decode_list_rejects_trailing_bytes() {
+        let mut encoded = encode_list(&[b"hi"]);
+        encoded.push(0xff);
+        assert!(decode_list(&encoded).is_err());
+    }
+
+    #[test]
+    fn test_decode_list_rejects_overlong_declared_length() {
+        // Claims a short list payload of 10 bytes but supplies none.
+        let encoded = vec![SHORT_LIST_BASE + 10];
+        assert!(decode_list(&encoded).is_err());
+    }
+
+    #[test]
+    fn test_decode_list_rejects_empty_input() {
+        assert!(decode_list(&[]).is_err());
+    }
+}
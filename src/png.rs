@@ -0,0 +1,311 @@
+use std::convert::TryFrom;
+use std::io::Write;
+use std::str::FromStr;
+
+use crate::chunk::{Chunk, ChunkReader, Decode, Encode};
+use crate::chunk_type::ChunkType;
+use crate::Result;
+
+const IHDR: &str = "IHDR";
+const IEND: &str = "IEND";
+
+/// An in-memory PNG file: the 8-byte signature plus an ordered list of
+/// chunks, with the structural invariants the PNG spec requires on that
+/// ordering enforced at construction time rather than left to callers.
+#[derive(Debug, PartialEq)]
+pub struct Png {
+    chunks: Vec<Chunk>,
+}
+
+impl Png {
+    pub const STANDARD_HEADER: [u8; 8] = [137, 80, 78, 71, 13, 10, 26, 10];
+
+    /// Builds a `Png` from an already-decoded chunk list, validating that
+    /// `IHDR` leads, `IEND` trails and appears exactly once, and no chunk
+    /// follows it.
+    pub fn from_chunks(chunks: Vec<Chunk>) -> Result<Png> {
+        Self::validate(&chunks)?;
+        Ok(Self { chunks })
+    }
+
+    pub fn chunks(&self) -> &[Chunk] {
+        &self.chunks
+    }
+
+    /// Inserts `chunk` immediately before `IEND`, which is the first-class
+    /// way to hide a custom chunk's payload in the steganography workflow.
+    /// Errors (leaving `self` unchanged) if the insertion would violate the
+    /// structural invariants `from_chunks` enforces, e.g. `chunk` is itself
+    /// an `IEND`.
+    pub fn append_chunk(&mut self, chunk: Chunk) -> Result<()> {
+        let iend_index = self
+            .chunks
+            .iter()
+            .position(|c| c.chunk_type().to_string() == IEND)
+            .ok_or_else(|| format!("no '{IEND}' chunk present"))?;
+
+        self.chunks.insert(iend_index, chunk);
+        if let Err(err) = Self::validate(&self.chunks) {
+            self.chunks.remove(iend_index);
+            return Err(err);
+        }
+
+        Ok(())
+    }
+
+    /// Removes and returns the first chunk of the given type, erroring if
+    /// none is present or if removing it would violate the structural
+    /// invariants `from_chunks` enforces (e.g. removing `IHDR` or `IEND`),
+    /// leaving `self` unchanged in that case.
+    pub fn remove_first_chunk(&mut self, chunk_type: &str) -> Result<Chunk> {
+        let parsed_type = ChunkType::from_str(chunk_type)?;
+        let index = self
+            .chunks
+            .iter()
+            .position(|chunk| *chunk.chunk_type() == parsed_type)
+            .ok_or_else(|| format!("no '{chunk_type}' chunk present"))?;
+
+        let removed = self.chunks.remove(index);
+        if let Err(err) = Self::validate(&self.chunks) {
+            self.chunks.insert(index, removed);
+            return Err(format!("cannot remove '{chunk_type}' chunk: {err}").into());
+        }
+
+        Ok(removed)
+    }
+
+    /// Returns the first chunk of the given type, if present.
+    pub fn chunk_by_type(&self, chunk_type: &str) -> Option<&Chunk> {
+        self.chunks
+            .iter()
+            .find(|chunk| chunk.chunk_type().to_string() == chunk_type)
+    }
+
+    pub fn as_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.encoded_len());
+        self.write_to(&mut bytes)
+            .expect("writing to a Vec<u8> cannot fail");
+        bytes
+    }
+
+    fn validate(chunks: &[Chunk]) -> Result<()> {
+        match chunks.first() {
+            Some(first) if first.chunk_type().to_string() == IHDR => {}
+            _ => return Err(format!("PNG must start with an '{IHDR}' chunk").into()),
+        }
+
+        let iend_count = chunks
+            .iter()
+            .filter(|chunk| chunk.chunk_type().to_string() == IEND)
+            .count();
+        if iend_count != 1 {
+            return Err(format!(
+                "PNG must contain exactly one '{IEND}' chunk, found {iend_count}"
+            )
+            .into());
+        }
+
+        match chunks.last() {
+            Some(last) if last.chunk_type().to_string() == IEND => {}
+            _ => return Err(format!("'{IEND}' must be the last chunk").into()),
+        }
+
+        Ok(())
+    }
+}
+
+impl Encode for Png {
+    fn encoded_len(&self) -> usize {
+        Self::STANDARD_HEADER.len()
+            + self
+                .chunks
+                .iter()
+                .map(Chunk::encoded_len)
+                .sum::<usize>()
+    }
+
+    fn write_to<W: Write>(&self, w: &mut W) -> Result<usize> {
+        w.write_all(&Self::STANDARD_HEADER)?;
+        let mut written = Self::STANDARD_HEADER.len();
+        for chunk in &self.chunks {
+            written += chunk.write_to(w)?;
+        }
+        Ok(written)
+    }
+}
+
+impl TryFrom<&[u8]> for Png {
+    type Error = crate::Error;
+
+    fn try_from(value: &[u8]) -> std::result::Result<Self, Self::Error> {
+        if value.len() < Self::STANDARD_HEADER.len() {
+            return Err("truncated PNG: missing signature".into());
+        }
+
+        let (signature, rest) = value.split_at(Self::STANDARD_HEADER.len());
+        if signature != Self::STANDARD_HEADER {
+            return Err("invalid PNG signature".into());
+        }
+
+        let mut reader = ChunkReader::new(rest);
+        let mut chunks = Vec::new();
+        loop {
+            let chunk = Chunk::decode(&mut reader)?;
+            let is_iend = chunk.chunk_type().to_string() == IEND;
+            chunks.push(chunk);
+            if is_iend || reader.remaining() == 0 {
+                break;
+            }
+        }
+
+        if reader.remaining() != 0 {
+            return Err(format!(
+                "{} byte(s) of trailing data after the final '{IEND}' chunk",
+                reader.remaining()
+            )
+            .into());
+        }
+
+        Png::from_chunks(chunks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chunk(chunk_type: &str, data: Vec<u8>) -> Chunk {
+        Chunk::new(ChunkType::from_str(chunk_type).unwrap(), data)
+    }
+
+    fn testing_png() -> Png {
+        Png::from_chunks(vec![
+            chunk(IHDR, vec![1, 2, 3, 4]),
+            chunk("IDAT", vec![5, 6, 7, 8]),
+            chunk(IEND, vec![]),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_from_chunks_valid() {
+        let png = testing_png();
+        assert_eq!(png.chunks().len(), 3);
+    }
+
+    #[test]
+    fn test_from_chunks_missing_ihdr_errs() {
+        let png = Png::from_chunks(vec![chunk("IDAT", vec![]), chunk(IEND, vec![])]);
+        assert!(png.is_err());
+    }
+
+    #[test]
+    fn test_from_chunks_missing_iend_errs() {
+        let png = Png::from_chunks(vec![chunk(IHDR, vec![]), chunk("IDAT", vec![])]);
+        assert!(png.is_err());
+    }
+
+    #[test]
+    fn test_from_chunks_duplicate_iend_errs() {
+        let png = Png::from_chunks(vec![
+            chunk(IHDR, vec![]),
+            chunk(IEND, vec![]),
+            chunk(IEND, vec![]),
+        ]);
+        assert!(png.is_err());
+    }
+
+    #[test]
+    fn test_from_chunks_trailing_chunk_after_iend_errs() {
+        let png = Png::from_chunks(vec![
+            chunk(IHDR, vec![]),
+            chunk(IEND, vec![]),
+            chunk("RuSt", vec![]),
+        ]);
+        assert!(png.is_err());
+    }
+
+    #[test]
+    fn test_try_from_bytes_round_trip() {
+        let png = testing_png();
+        let bytes = png.as_bytes();
+
+        let decoded = Png::try_from(bytes.as_ref()).unwrap();
+        assert_eq!(decoded, png);
+    }
+
+    #[test]
+    fn test_try_from_invalid_signature_errs() {
+        let mut bytes = testing_png().as_bytes();
+        bytes[0] = 0;
+        assert!(Png::try_from(bytes.as_ref()).is_err());
+    }
+
+    #[test]
+    fn test_try_from_truncated_signature_errs() {
+        assert!(Png::try_from(&[137, 80, 78][..]).is_err());
+    }
+
+    #[test]
+    fn test_try_from_trailing_data_after_iend_errs() {
+        let mut bytes = testing_png().as_bytes();
+        bytes.extend(chunk("ruSt", vec![]).as_bytes());
+
+        assert!(Png::try_from(bytes.as_ref()).is_err());
+    }
+
+    #[test]
+    fn test_append_chunk_inserts_before_iend() {
+        let mut png = testing_png();
+        png.append_chunk(chunk("ruSt", b"secret".to_vec())).unwrap();
+
+        assert_eq!(png.chunks()[2].chunk_type().to_string(), "ruSt");
+        assert_eq!(png.chunks().last().unwrap().chunk_type().to_string(), IEND);
+    }
+
+    #[test]
+    fn test_append_chunk_rejects_duplicate_iend() {
+        let mut png = testing_png();
+        assert!(png.append_chunk(chunk(IEND, vec![])).is_err());
+        assert_eq!(png, testing_png());
+    }
+
+    #[test]
+    fn test_remove_first_chunk() {
+        let mut png = testing_png();
+        let removed = png.remove_first_chunk("IDAT").unwrap();
+
+        assert_eq!(removed.chunk_type().to_string(), "IDAT");
+        assert!(png.chunk_by_type("IDAT").is_none());
+    }
+
+    #[test]
+    fn test_remove_first_chunk_missing_errs() {
+        let mut png = testing_png();
+        assert!(png.remove_first_chunk("ruSt").is_err());
+    }
+
+    #[test]
+    fn test_remove_first_chunk_rejects_ihdr() {
+        let mut png = testing_png();
+        assert!(png.remove_first_chunk(IHDR).is_err());
+        assert_eq!(png, testing_png());
+    }
+
+    #[test]
+    fn test_remove_first_chunk_rejects_iend() {
+        let mut png = testing_png();
+        assert!(png.remove_first_chunk(IEND).is_err());
+        assert_eq!(png, testing_png());
+    }
+
+    #[test]
+    fn test_chunk_by_type() {
+        let png = testing_png();
+        assert_eq!(
+            png.chunk_by_type("IDAT").unwrap().data(),
+            &[5, 6, 7, 8][..]
+        );
+        assert!(png.chunk_by_type("ruSt").is_none());
+    }
+}